@@ -1,10 +1,11 @@
 use quote::ToTokens;
+use regex::Regex;
 use serde::Deserialize;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fs::{self, File},
     io::{self, BufReader, Read},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 use walkdir::{DirEntry, WalkDir};
 
@@ -52,6 +53,26 @@ pub struct CargoToml {
     pub dependencies: Option<HashMap<String, toml::Value>>,
     #[serde(rename = "dev-dependencies")]
     pub dev_dependencies: Option<HashMap<String, toml::Value>>,
+    #[serde(rename = "build-dependencies")]
+    pub build_dependencies: Option<HashMap<String, toml::Value>>,
+    pub workspace: Option<CargoWorkspace>,
+    /// `[features] name = [...]`; each list element is either another
+    /// feature name or a `dep:name` reference to an optional dependency.
+    pub features: Option<HashMap<String, Vec<String>>>,
+    /// `[target.'cfg(...)'.dependencies]` and friends, keyed by the cfg
+    /// expression or target triple string.
+    pub target: Option<HashMap<String, TargetDeps>>,
+}
+
+/// The dependency tables nested under a single `[target.'<cfg-or-triple>']`
+/// key, mirroring the shape Cargo itself uses for platform-specific deps.
+#[derive(Deserialize, Debug)]
+pub struct TargetDeps {
+    pub dependencies: Option<HashMap<String, toml::Value>>,
+    #[serde(rename = "dev-dependencies")]
+    pub dev_dependencies: Option<HashMap<String, toml::Value>>,
+    #[serde(rename = "build-dependencies")]
+    pub build_dependencies: Option<HashMap<String, toml::Value>>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -61,12 +82,98 @@ pub struct CargoPackage {
     pub description: Option<String>,
 }
 
+/// The `[workspace]` table of a root, possibly-virtual manifest.
+#[derive(Deserialize, Debug)]
+pub struct CargoWorkspace {
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Cargo.lock
+#[derive(Deserialize, Debug)]
+pub struct CargoLock {
+    #[serde(rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// A resolved view of a `Cargo.lock`: the exact version each package name
+/// locked to, plus which locked packages depend on which. Keyed by
+/// `BTreeMap` rather than `HashMap` so iterating it (as `summarizer` does to
+/// render the "Locked: ..." lines) produces the same order on every run.
+pub struct ResolvedDependencies {
+    /// package name -> locked version
+    pub versions: BTreeMap<String, String>,
+    /// package name -> names of packages that depend on it
+    pub reverse_edges: BTreeMap<String, Vec<String>>,
+    /// package name -> registry/git source, for locked packages that record one
+    pub sources: BTreeMap<String, String>,
+}
+
+impl CargoLock {
+    pub fn parse(file: File) -> Result<Self, RepoError> {
+        let mut reader = BufReader::new(file);
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        let lock: CargoLock = toml::from_str(&contents)?;
+        Ok(lock)
+    }
+
+    /// Build a resolved name -> version map and the reverse dependency
+    /// edges (who depends on whom), used to report a transitive count.
+    pub fn resolve(&self) -> ResolvedDependencies {
+        let mut versions = BTreeMap::new();
+        let mut reverse_edges: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut sources = BTreeMap::new();
+
+        for pkg in &self.packages {
+            versions.insert(pkg.name.clone(), pkg.version.clone());
+            if let Some(source) = &pkg.source {
+                sources.insert(pkg.name.clone(), source.clone());
+            }
+        }
+
+        for pkg in &self.packages {
+            for dep in &pkg.dependencies {
+                // Entries are "name" or "name version"; we only need the name.
+                let dep_name = dep.split_whitespace().next().unwrap_or(dep);
+                reverse_edges
+                    .entry(dep_name.to_string())
+                    .or_default()
+                    .push(pkg.name.clone());
+            }
+        }
+
+        ResolvedDependencies {
+            versions,
+            reverse_edges,
+            sources,
+        }
+    }
+}
+
 pub struct FileInformation {
     pub file_name: String,
+    /// The file's `//!` module-level doc comment, if any.
+    pub module_doc: Option<String>,
     pub structs: HashMap<String, Vec<String>>,
+    /// `///` doc comments for each struct, keyed by struct name.
+    pub struct_docs: HashMap<String, String>,
     pub functions: HashMap<String, FunctionMeta>,
     pub variables: Vec<String>,
     pub enums: HashMap<String, Vec<String>>,
+    /// `///` doc comments for each enum, keyed by enum name.
+    pub enum_docs: HashMap<String, String>,
     pub others: Vec<String>, // e.g. comments
 }
 
@@ -74,6 +181,8 @@ pub struct FunctionMeta {
     pub params: Vec<String>,
     pub returns: String,
     pub visibility: String,
+    /// The function's `///` doc comment, if any.
+    pub doc: Option<String>,
 }
 
 pub struct RepoCodeContext {
@@ -82,6 +191,28 @@ pub struct RepoCodeContext {
     pub files: Vec<FileInformation>,
     pub folders: Vec<String>,
     pub dependencies: Vec<CargoToml>,
+    pub locked_dependencies: Option<ResolvedDependencies>,
+    /// Populated instead of `files`/`dependencies` when the repo root is a
+    /// Cargo workspace: one independently-resolved sub-context per member.
+    pub workspace_members: Vec<CrateContext>,
+}
+
+/// A single crate resolved from a `[workspace]` member glob, parsed and
+/// reported independently of its siblings.
+pub struct CrateContext {
+    pub name: String,
+    pub files: Vec<FileInformation>,
+    pub dependencies: Vec<CargoToml>,
+}
+
+impl CrateContext {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            files: Vec::new(),
+            dependencies: Vec::new(),
+        }
+    }
 }
 
 /// Implementation for parsing Cargo.toml
@@ -101,10 +232,13 @@ impl FileInformation {
     pub fn new(file_name: String) -> Self {
         Self {
             file_name,
+            module_doc: None,
             structs: HashMap::new(),
+            struct_docs: HashMap::new(),
             functions: HashMap::new(),
             variables: Vec::new(),
             enums: HashMap::new(),
+            enum_docs: HashMap::new(),
             others: Vec::new(),
         }
     }
@@ -118,16 +252,24 @@ impl RepoCodeContext {
             languages: HashMap::new(),
             files: Vec::new(),
             dependencies: Vec::new(),
+            locked_dependencies: None,
+            workspace_members: Vec::new(),
         }
     }
 }
 
 impl FunctionMeta {
-    pub fn new(params: Vec<String>, visibility: String, returns: String) -> Self {
+    pub fn new(
+        params: Vec<String>,
+        visibility: String,
+        returns: String,
+        doc: Option<String>,
+    ) -> Self {
         Self {
             params,
             visibility,
             returns,
+            doc,
         }
     }
 }
@@ -151,31 +293,112 @@ fn is_cargo_toml(entry: &DirEntry) -> bool {
         .unwrap_or(false)
 }
 
+fn is_cargo_lock(entry: &DirEntry) -> bool {
+    entry
+        .file_name()
+        .to_str()
+        .map(|s| s == "Cargo.lock")
+        .unwrap_or(false)
+}
+
 fn map_extension_to_language(ext: &str) -> String {
     match ext {
         "rs" => "Rust".to_string(),
+        "py" => "Python".to_string(),
         _ => ext.to_string(),
     }
 }
 
-/// Parse a Rust source file and collect information.
-/// Returns a Result wrapping FileInformation.
-fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
-    let file_name = entry
+/// A per-language parser that turns one source file into the same
+/// `FileInformation` shape the LLM prompt is built from, regardless of
+/// which language it came from.
+trait LanguageParser {
+    fn parse(&self, entry: &DirEntry) -> Result<FileInformation, RepoError>;
+}
+
+struct RustParser;
+
+impl LanguageParser for RustParser {
+    fn parse(&self, entry: &DirEntry) -> Result<FileInformation, RepoError> {
+        parse_rust_file(entry)
+    }
+}
+
+/// Lightweight regex-based fallback for languages without a `syn`-grade
+/// parser available: good enough to surface top-level names and
+/// signatures without needing a full grammar.
+struct PythonParser;
+
+impl LanguageParser for PythonParser {
+    fn parse(&self, entry: &DirEntry) -> Result<FileInformation, RepoError> {
+        parse_python_file(entry)
+    }
+}
+
+/// Dispatch table from file extension to the parser that handles it.
+/// Extensions with no entry are counted in `languages` and skipped.
+fn parser_for_extension(ext: &str) -> Option<Box<dyn LanguageParser>> {
+    match ext {
+        "rs" => Some(Box::new(RustParser)),
+        "py" => Some(Box::new(PythonParser)),
+        _ => None,
+    }
+}
+
+/// Pull the text out of a run of `#[doc = "..."]` attributes (the form
+/// both `///` and `//!` comments desugar to) and join multi-line runs with
+/// newlines, trimming the whitespace around each line (such as the space
+/// that usually follows `///`).
+fn extract_doc(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                }) => Some(lit_str.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Pull a `DirEntry`'s file name out as an owned `String`, the one piece of
+/// boilerplate every per-language parser needs before it can build a
+/// `FileInformation`.
+fn file_name_of(entry: &DirEntry) -> Result<String, RepoError> {
+    entry
         .path()
         .file_name()
         .and_then(|s| s.to_str())
         .map(String::from)
-        .ok_or_else(|| RepoError::Io(io::Error::new(io::ErrorKind::Other, "Invalid file name")))?;
+        .ok_or_else(|| RepoError::Io(io::Error::other("Invalid file name")))
+}
+
+/// Parse a Rust source file and collect information.
+/// Returns a Result wrapping FileInformation.
+fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
+    let file_name = file_name_of(entry)?;
 
     let src = fs::read_to_string(entry.path())?;
     let syntax_tree: syn::File = syn::parse_str(&src)?;
 
     let mut file_info = FileInformation::new(file_name);
+    file_info.module_doc = extract_doc(&syntax_tree.attrs);
 
     for item in syntax_tree.items {
         match item {
             syn::Item::Fn(func) => {
+                let func_doc = extract_doc(&func.attrs);
                 let func_name = func.sig.ident.to_string();
                 let func_vis = match func.vis {
                     syn::Visibility::Public(_) => "public",
@@ -198,7 +421,7 @@ fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
                     }
                 }
 
-                let fn_meta = FunctionMeta::new(params, func_vis, func_output);
+                let fn_meta = FunctionMeta::new(params, func_vis, func_output, func_doc);
                 file_info.functions.insert(func_name, fn_meta);
             }
             syn::Item::Const(var) => {
@@ -206,12 +429,17 @@ fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
                 file_info.variables.push(const_name);
             }
             syn::Item::Enum(en) => {
+                let enum_doc = extract_doc(&en.attrs);
                 let enum_name = en.ident.to_string();
                 let enum_fields: Vec<String> =
                     en.variants.iter().map(|v| v.ident.to_string()).collect();
+                if let Some(doc) = enum_doc {
+                    file_info.enum_docs.insert(enum_name.clone(), doc);
+                }
                 file_info.enums.insert(enum_name, enum_fields);
             }
             syn::Item::Struct(struc) => {
+                let struct_doc = extract_doc(&struc.attrs);
                 let struct_name = struc.ident.to_string();
                 let mut struct_fields: Vec<String> = Vec::new();
                 match struc.fields {
@@ -230,6 +458,9 @@ fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
                     }
                     syn::Fields::Unit => {}
                 }
+                if let Some(doc) = struct_doc {
+                    file_info.struct_docs.insert(struct_name.clone(), doc);
+                }
                 file_info.structs.insert(struct_name, struct_fields);
             }
             syn::Item::Static(var) => {
@@ -243,6 +474,145 @@ fn parse_rust_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
     Ok(file_info)
 }
 
+/// Parse a Python source file with a regex-based fallback: good enough to
+/// surface top-level `def`s and `class`es without a real grammar.
+fn parse_python_file(entry: &DirEntry) -> Result<FileInformation, RepoError> {
+    let file_name = file_name_of(entry)?;
+
+    let src = fs::read_to_string(entry.path())?;
+    let mut file_info = FileInformation::new(file_name);
+
+    let def_re = Regex::new(r"(?m)^def\s+(\w+)\s*\(([^)]*)\)").unwrap();
+    for caps in def_re.captures_iter(&src) {
+        let func_name = caps[1].to_string();
+        let params: Vec<String> = caps[2]
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        let fn_meta = FunctionMeta::new(params, "public".to_string(), "None".to_string(), None);
+        file_info.functions.insert(func_name, fn_meta);
+    }
+
+    let class_re = Regex::new(r"(?m)^class\s+(\w+)\s*(?:\(([^)]*)\))?:").unwrap();
+    for caps in class_re.captures_iter(&src) {
+        let class_name = caps[1].to_string();
+        let bases: Vec<String> = caps
+            .get(2)
+            .map(|bases| {
+                bases
+                    .as_str()
+                    .split(',')
+                    .map(|b| b.trim().to_string())
+                    .filter(|b| !b.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        file_info.structs.insert(class_name, bases);
+    }
+
+    Ok(file_info)
+}
+
+/// Expand `[workspace] members`/`exclude` glob patterns against the repo
+/// root. Only the common `dir/*` shape (one level of wildcard, as in
+/// `examples/*`) is treated as a glob; anything else is a literal path.
+fn expand_member_globs(repo_root: &Path, members: &[String], exclude: &[String]) -> Vec<PathBuf> {
+    let mut resolved = Vec::new();
+
+    for pattern in members {
+        if let Some(prefix) = pattern.strip_suffix("/*") {
+            let base = repo_root.join(prefix);
+            if let Ok(entries) = fs::read_dir(&base) {
+                for entry in entries.filter_map(Result::ok) {
+                    if entry.path().is_dir() {
+                        resolved.push(entry.path());
+                    }
+                }
+            }
+        } else {
+            resolved.push(repo_root.join(pattern));
+        }
+    }
+
+    resolved.retain(|path| {
+        !exclude.iter().any(|excluded| match excluded.strip_suffix("/*") {
+            Some(prefix) => path.parent() == Some(repo_root.join(prefix).as_path()),
+            None => path.ends_with(excluded),
+        })
+    });
+    resolved
+}
+
+/// Parse a single workspace member crate: its own `Cargo.toml` (if any) and
+/// every source file beneath it that has a language parser, independent of
+/// its sibling crates.
+fn build_crate_context(crate_dir: &Path) -> Result<CrateContext, RepoError> {
+    let mut name = crate_dir
+        .file_name()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut context = CrateContext::new(name.clone());
+
+    let manifest_path = crate_dir.join("Cargo.toml");
+    if manifest_path.is_file() {
+        let file = File::open(&manifest_path)?;
+        let cargo_toml = CargoToml::parse(file)?;
+        if let Some(pkg) = &cargo_toml.package {
+            name = pkg.name.clone();
+        }
+        context.dependencies.push(cargo_toml);
+        context.name = name;
+    }
+
+    for entry in WalkDir::new(crate_dir).into_iter().filter_map(Result::ok) {
+        if entry.file_type().is_file() && !invalid_path(&entry) {
+            if let Some(ext) = entry.path().extension().and_then(|s| s.to_str()) {
+                if let Some(parser) = parser_for_extension(ext) {
+                    context.files.push(parser.parse(&entry)?);
+                }
+            }
+        }
+    }
+
+    Ok(context)
+}
+
+/// Parse the root crate of a workspace whose manifest has both `[workspace]`
+/// and `[package]`: the already-parsed root `Cargo.toml` becomes this
+/// crate's sole dependency entry, and its source files with a language
+/// parser are walked directly
+/// (skipping anything under a resolved member directory, since those parse
+/// themselves in `build_crate_context`).
+fn build_root_crate_context(
+    dir_path: &Path,
+    cargo_toml: CargoToml,
+    member_dirs: &[PathBuf],
+) -> Result<CrateContext, RepoError> {
+    let name = cargo_toml
+        .package
+        .as_ref()
+        .map(|pkg| pkg.name.clone())
+        .unwrap_or_default();
+    let mut context = CrateContext::new(name);
+    context.dependencies.push(cargo_toml);
+
+    for entry in WalkDir::new(dir_path).into_iter().filter_map(Result::ok) {
+        if entry.file_type().is_file()
+            && !invalid_path(&entry)
+            && !member_dirs.iter().any(|m| entry.path().starts_with(m))
+        {
+            if let Some(ext) = entry.path().extension().and_then(|s| s.to_str()) {
+                if let Some(parser) = parser_for_extension(ext) {
+                    context.files.push(parser.parse(&entry)?);
+                }
+            }
+        }
+    }
+
+    Ok(context)
+}
+
 /// Main traversal logic that walks through a repository
 /// and gathers information. Returns a Result wrapping RepoCodeContext.
 pub fn walk_repo(dir_path: PathBuf) -> Result<RepoCodeContext, RepoError> {
@@ -253,12 +623,69 @@ pub fn walk_repo(dir_path: PathBuf) -> Result<RepoCodeContext, RepoError> {
         .to_string();
     let mut repo = RepoCodeContext::new(repo_name);
 
+    // A root `Cargo.toml` with a `[workspace]` table and no `[package]` is a
+    // virtual manifest: resolve each member crate independently instead of
+    // lumping every file in the repo into one flat `files`/`dependencies`.
+    // A root manifest with *both* `[workspace]` and `[package]` (the common
+    // "root binary + path-dependency members" layout) additionally resolves
+    // the root directory itself as one more crate context alongside its
+    // members, instead of discarding the root's own package/dependencies.
+    let root_manifest = dir_path.join("Cargo.toml");
+    if root_manifest.is_file() {
+        let file = File::open(&root_manifest)?;
+        let cargo_toml = CargoToml::parse(file)?;
+        if let Some(ws) = &cargo_toml.workspace {
+            let member_dirs = expand_member_globs(&dir_path, &ws.members, &ws.exclude);
+            let has_root_package = cargo_toml.package.is_some();
+            if has_root_package {
+                repo.workspace_members.push(build_root_crate_context(
+                    &dir_path,
+                    cargo_toml,
+                    &member_dirs,
+                )?);
+            }
+            for member_dir in member_dirs {
+                repo.workspace_members
+                    .push(build_crate_context(&member_dir)?);
+            }
+        } else {
+            repo.dependencies.push(cargo_toml);
+        }
+    }
+
     for entry in WalkDir::new(&dir_path).into_iter().filter_map(Result::ok) {
         if entry.file_type().is_file() && !invalid_path(&entry) {
+            // Already resolved above, either as the workspace root or the
+            // sole crate manifest.
+            if entry.path() == root_manifest {
+                continue;
+            }
+
             if let Some(ext) = entry.path().extension().and_then(|s| s.to_str()) {
                 let lang = map_extension_to_language(ext);
                 *repo.languages.entry(lang.clone()).or_insert(0) += 1;
 
+                if is_cargo_lock(&entry) {
+                    let file = File::open(entry.path())?;
+                    let cargo_lock = CargoLock::parse(file)?;
+                    repo.locked_dependencies = Some(cargo_lock.resolve());
+                    continue;
+                }
+
+                // Whether this repo resolved as a workspace is read off
+                // `workspace_members` itself (rather than a separate flag)
+                // so this check can never disagree with `build_input`'s own
+                // `workspace_members.is_empty()` branch: if the `members`
+                // glob matched nothing (or every match was excluded), there
+                // is nothing to skip and every file is parsed flat below.
+                if !repo.workspace_members.is_empty() {
+                    // Every resolved crate (the root crate, if it doubled
+                    // as a workspace member, and each member glob match)
+                    // already parsed its own Cargo.toml and source files in
+                    // `build_crate_context`/`build_root_crate_context` above.
+                    continue;
+                }
+
                 // Handle dependency files
                 if is_cargo_toml(&entry) {
                     let file = File::open(entry.path())?;
@@ -266,15 +693,12 @@ pub fn walk_repo(dir_path: PathBuf) -> Result<RepoCodeContext, RepoError> {
                     repo.dependencies.push(cargo_file);
                 }
 
-                // Parse Rust source files
-                if ext == "rs" {
-                    let file_info = parse_rust_file(&entry)?;
+                // Parse source files with whichever language parser
+                // handles this extension; unknown extensions are already
+                // counted in `languages` above, so just skip them.
+                if let Some(parser) = parser_for_extension(ext) {
+                    let file_info = parser.parse(&entry)?;
                     repo.files.push(file_info);
-                } else {
-                    todo!(
-                        "Parsing for files with extension '{}' is not implemented",
-                        ext
-                    );
                 }
             }
         } else if entry.file_type().is_dir() && !invalid_path(&entry) {
@@ -286,3 +710,116 @@ pub fn walk_repo(dir_path: PathBuf) -> Result<RepoCodeContext, RepoError> {
 
     Ok(repo)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_builds_versions_reverse_edges_and_sources() {
+        let lock = CargoLock {
+            packages: vec![
+                LockedPackage {
+                    name: "crate-a".to_string(),
+                    version: "0.1.0".to_string(),
+                    source: None,
+                    dependencies: vec!["serde 1.0.0".to_string()],
+                },
+                LockedPackage {
+                    name: "serde".to_string(),
+                    version: "1.0.0".to_string(),
+                    source: Some("registry+https://github.com/rust-lang/crates.io-index".to_string()),
+                    dependencies: vec![],
+                },
+            ],
+        };
+
+        let resolved = lock.resolve();
+
+        assert_eq!(resolved.versions.get("crate-a"), Some(&"0.1.0".to_string()));
+        assert_eq!(resolved.versions.get("serde"), Some(&"1.0.0".to_string()));
+        assert_eq!(
+            resolved.reverse_edges.get("serde"),
+            Some(&vec!["crate-a".to_string()])
+        );
+        assert_eq!(resolved.sources.get("crate-a"), None);
+        assert_eq!(
+            resolved.sources.get("serde"),
+            Some(&"registry+https://github.com/rust-lang/crates.io-index".to_string())
+        );
+    }
+
+    #[test]
+    fn expand_member_globs_resolves_literals_globs_and_exclude() {
+        let root = std::env::temp_dir().join("readme_gen_test_expand_member_globs");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("member-a")).unwrap();
+        fs::create_dir_all(root.join("examples/foo")).unwrap();
+        fs::create_dir_all(root.join("examples/bar")).unwrap();
+
+        let members = vec!["member-a".to_string(), "examples/*".to_string()];
+        let exclude = vec!["examples/bar".to_string()];
+        let mut resolved = expand_member_globs(&root, &members, &exclude);
+        resolved.sort();
+
+        let mut expected = vec![root.join("examples/foo"), root.join("member-a")];
+        expected.sort();
+        assert_eq!(resolved, expected);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn extract_doc_joins_multiline_triple_slash_comments() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"
+            /// First line.
+            /// Second line.
+            fn documented() {}
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            extract_doc(&item.attrs),
+            Some("First line.\nSecond line.".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_doc_is_none_without_doc_attrs() {
+        let item: syn::ItemFn = syn::parse_str("fn undocumented() {}").unwrap();
+        assert_eq!(extract_doc(&item.attrs), None);
+    }
+
+    #[test]
+    fn parse_python_file_extracts_functions_and_classes() {
+        let dir = std::env::temp_dir().join("readme_gen_test_parse_python_file");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file_path = dir.join("example.py");
+        fs::write(
+            &file_path,
+            "def greet(name, loud=False):\n    pass\n\nclass Greeter(Base):\n    pass\n",
+        )
+        .unwrap();
+
+        let entry = WalkDir::new(&dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .find(|e| e.path() == file_path)
+            .expect("fixture file should be found by WalkDir");
+
+        let file_info = parse_python_file(&entry).unwrap();
+
+        assert_eq!(file_info.file_name, "example.py");
+        let greet = file_info.functions.get("greet").expect("greet function");
+        assert_eq!(greet.params, vec!["name", "loud=False"]);
+        assert_eq!(
+            file_info.structs.get("Greeter"),
+            Some(&vec!["Base".to_string()])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,8 +1,18 @@
-use crate::summarizer;
+use crate::summarizer::{self, LLMInput};
+use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::{env, path::PathBuf};
 
+/// A pluggable README-generation backend: given the rendered repo prompt
+/// (the JSON-serialized `LLMInput`), produce the markdown. Implementations
+/// range from a hosted LLM API to a deterministic offline template, so the
+/// generator keeps working without a network connection or API key.
+#[async_trait]
+pub trait LlmBackend {
+    async fn complete(&self, prompt: String) -> Result<String, Box<dyn std::error::Error>>;
+}
+
 #[derive(Serialize)]
 struct GeminiRequest {
     contents: Vec<Content>,
@@ -39,6 +49,199 @@ struct PartResponse {
     text: String,
 }
 
+/// Google's Gemini `generateContent` API, read from `GEMINI_API_KEY`.
+pub struct GeminiBackend {
+    api_key: String,
+    client: Client,
+}
+
+impl GeminiBackend {
+    pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+        let api_key = env::var("GEMINI_API_KEY")
+            .map_err(|_| "Please set the GEMINI_API_KEY environment variable.")?;
+        Ok(Self {
+            api_key,
+            client: Client::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl LlmBackend for GeminiBackend {
+    async fn complete(&self, prompt: String) -> Result<String, Box<dyn std::error::Error>> {
+        let request_body = GeminiRequest {
+            contents: vec![Content {
+                role: "user".to_string(),
+                parts: vec![Part { text: prompt }],
+            }],
+        };
+
+        let response = self
+            .client
+            .post(format!(
+                "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent?key={}",
+                self.api_key
+            ))
+            .json(&request_body)
+            .send()
+            .await?;
+
+        let response_data: GeminiResponse = response.json().await?;
+
+        response_data
+            .candidates
+            .first()
+            .and_then(|c| c.content.parts.first())
+            .map(|part| part.text.clone())
+            .ok_or_else(|| "No markdown content generated".into())
+    }
+}
+
+#[derive(Serialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct ChatCompletionRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatMessageResponse,
+}
+
+#[derive(Deserialize)]
+struct ChatMessageResponse {
+    content: String,
+}
+
+/// Any OpenAI-compatible `/chat/completions` endpoint (OpenAI itself, or a
+/// self-hosted server such as Ollama, vLLM, or LM Studio). The base URL and
+/// model are configurable so users can point this at a local server without
+/// an API key.
+pub struct OpenAiBackend {
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+    client: Client,
+}
+
+impl OpenAiBackend {
+    pub fn from_env() -> Self {
+        Self {
+            base_url: env::var("OPENAI_BASE_URL")
+                .unwrap_or_else(|_| "https://api.openai.com/v1".to_string()),
+            api_key: env::var("OPENAI_API_KEY").ok(),
+            model: env::var("OPENAI_MODEL").unwrap_or_else(|_| "gpt-4o-mini".to_string()),
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OpenAiBackend {
+    async fn complete(&self, prompt: String) -> Result<String, Box<dyn std::error::Error>> {
+        let request_body = ChatCompletionRequest {
+            model: self.model.clone(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: prompt,
+            }],
+        };
+
+        let mut request = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .json(&request_body);
+
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().await?;
+        let response_data: ChatCompletionResponse = response.json().await?;
+
+        response_data
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .ok_or_else(|| "No markdown content generated".into())
+    }
+}
+
+/// Deterministic, offline fallback: renders a README directly from the
+/// `LLMInput` the prompt was built from, without a network call. Lets the
+/// tool degrade gracefully and keeps it testable without a live backend.
+pub struct TemplateBackend;
+
+#[async_trait]
+impl LlmBackend for TemplateBackend {
+    async fn complete(&self, prompt: String) -> Result<String, Box<dyn std::error::Error>> {
+        let input: LLMInput = serde_json::from_str(&prompt)?;
+        Ok(render_template(&input))
+    }
+}
+
+fn render_template(input: &LLMInput) -> String {
+    let mut md = format!("# {}\n\n", input.project_name);
+
+    md.push_str(&format!("Language: {}\n\n", input.project_language));
+
+    if !input.project_dependencies.is_empty() {
+        md.push_str("## Dependencies\n\n");
+        for dep in &input.project_dependencies {
+            md.push_str(&format!("- {}\n", dep));
+        }
+        md.push('\n');
+    }
+
+    if !input.project_features.is_empty() {
+        md.push_str("## Features\n\n");
+        for feature in &input.project_features {
+            md.push_str(&format!("- {}\n", feature));
+        }
+        md.push('\n');
+    }
+
+    md.push_str("## Project Structure\n\n");
+    for line in &input.project_structure {
+        md.push_str(&format!("- {}\n", line));
+    }
+
+    md
+}
+
+/// Select a backend from an explicit CLI flag (e.g. `--backend openai`),
+/// falling back to the `LLM_BACKEND` environment variable, and defaulting
+/// to Gemini to preserve the tool's original behavior.
+pub fn select_backend(cli_flag: Option<&str>) -> Result<Box<dyn LlmBackend>, Box<dyn std::error::Error>> {
+    let backend_name = cli_flag
+        .map(|s| s.to_string())
+        .or_else(|| env::var("LLM_BACKEND").ok())
+        .unwrap_or_else(|| "gemini".to_string());
+
+    match backend_name.as_str() {
+        "gemini" => Ok(Box::new(GeminiBackend::from_env()?)),
+        "openai" => Ok(Box::new(OpenAiBackend::from_env())),
+        "template" => Ok(Box::new(TemplateBackend)),
+        other => Err(format!(
+            "Unknown LLM backend '{}'. Expected one of: gemini, openai, template.",
+            other
+        )
+        .into()),
+    }
+}
+
 fn load_summarizer(path: PathBuf) -> Result<String, Box<dyn std::error::Error>> {
     summarizer::build_input(path).map_err(|e| {
         eprintln!("Error building input: {}", e);
@@ -46,10 +249,10 @@ fn load_summarizer(path: PathBuf) -> Result<String, Box<dyn std::error::Error>>
     })
 }
 
-pub async fn generate_md(path: PathBuf) -> Result<String, Box<dyn std::error::Error>> {
-    let api_key =
-        env::var("GEMINI_API_KEY").expect("Please set the GEMINI_API_KEY environment variable.");
-
+pub async fn generate_md(
+    path: PathBuf,
+    backend: &dyn LlmBackend,
+) -> Result<String, Box<dyn std::error::Error>> {
     let prompt = match load_summarizer(path.clone()) {
         Ok(p) => p,
         Err(_) => {
@@ -57,33 +260,43 @@ pub async fn generate_md(path: PathBuf) -> Result<String, Box<dyn std::error::Er
         }
     };
 
-    let request_body = GeminiRequest {
-        contents: vec![Content {
-            role: "user".to_string(),
-            parts: vec![Part { text: prompt }],
-        }],
-    };
+    backend.complete(prompt).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> LLMInput {
+        LLMInput {
+            prompt_directives: "You are a README generator.".to_string(),
+            project_name: "demo".to_string(),
+            project_language: "Rust: 2 files".to_string(),
+            project_dependencies: vec!["Dependency: serde: \"1.0\"".to_string()],
+            project_features: vec!["Default features: [foo]".to_string()],
+            project_structure: vec!["Folders: src".to_string()],
+        }
+    }
+
+    #[test]
+    fn render_template_includes_name_deps_and_features() {
+        let md = render_template(&sample_input());
+        assert!(md.starts_with("# demo\n"));
+        assert!(md.contains("## Dependencies"));
+        assert!(md.contains("- Dependency: serde: \"1.0\""));
+        assert!(md.contains("## Features"));
+        assert!(md.contains("- Default features: [foo]"));
+        assert!(md.contains("## Project Structure"));
+        assert!(md.contains("- Folders: src"));
+    }
+
+    #[tokio::test]
+    async fn template_backend_round_trips_the_serialized_prompt() {
+        let input = sample_input();
+        let prompt = serde_json::to_string(&input).unwrap();
+
+        let markdown = TemplateBackend.complete(prompt).await.unwrap();
 
-    let client = Client::new();
-
-    let response = client
-        .post(format!(
-            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent?key={}",
-            api_key
-        ))
-        .json(&request_body)
-        .send()
-        .await?;
-
-    let response_data: GeminiResponse = response.json().await?;
-
-    if let Some(part) = response_data
-        .candidates
-        .first()
-        .and_then(|c| c.content.parts.first())
-    {
-        Ok(part.text.clone())
-    } else {
-        Err("No markdown content generated".into())
+        assert_eq!(markdown, render_template(&input));
     }
 }
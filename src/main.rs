@@ -11,13 +11,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        eprintln!("Usage: {} <folder_path>", args[0]);
+        eprintln!("Usage: {} <folder_path> [--backend <gemini|openai|template>]", args[0]);
         return Ok(());
     }
 
     let folder_path = PathBuf::from(&args[1]);
+    let backend_flag = args
+        .iter()
+        .position(|arg| arg == "--backend")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str);
 
-    let markdown_content = llm::generate_md(folder_path).await?;
+    let backend = llm::select_backend(backend_flag)?;
+    let markdown_content = llm::generate_md(folder_path, backend.as_ref()).await?;
 
     std::fs::write("README.md", &markdown_content)?;
     println!("Markdown file 'README.md' generated successfully!");
@@ -1,15 +1,20 @@
-use crate::explorer;
-use serde::Serialize;
+use crate::explorer::{self, CargoToml, FileInformation, ResolvedDependencies};
+use serde::{Deserialize, Serialize};
 use serde_json;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
-#[derive(Serialize, Debug, Default)]
-struct LLMInput {
-    prompt_directives: String,
-    project_name: String,
-    project_language: String,
-    project_dependencies: String,
-    project_structure: Vec<String>,
+/// The structured view of a repo handed to an `LlmBackend`. `Deserialize`
+/// lets an offline backend round-trip the rendered prompt back into this
+/// shape instead of re-walking the repo itself.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub(crate) struct LLMInput {
+    pub(crate) prompt_directives: String,
+    pub(crate) project_name: String,
+    pub(crate) project_language: String,
+    pub(crate) project_dependencies: Vec<String>,
+    pub(crate) project_features: Vec<String>,
+    pub(crate) project_structure: Vec<String>,
 }
 
 impl LLMInput {
@@ -18,7 +23,184 @@ impl LLMInput {
     }
 }
 
-fn build_input(path: PathBuf) -> Result<String, Box<dyn std::error::Error>> {
+/// Render a single parsed file's functions/structs/variables/enums as one
+/// line of the prompt's `project_structure`, grounding each in the
+/// author's own doc comments where they exist instead of leaving the
+/// model to invent descriptions from identifier names alone.
+fn describe_file(file: &FileInformation) -> String {
+    let functions_detail = file
+        .functions
+        .iter()
+        .map(|(name, meta)| {
+            let doc = meta
+                .doc
+                .as_ref()
+                .map_or(String::new(), |d| format!(" — {}", d));
+            format!(
+                "{} (params: [{}], returns: {}, visibility: {}){}",
+                name,
+                meta.params.join(", "),
+                meta.returns,
+                meta.visibility,
+                doc
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ; ");
+
+    let structs_detail = file
+        .structs
+        .keys()
+        .map(|name| match file.struct_docs.get(name) {
+            Some(doc) => format!("{} — {}", name, doc),
+            None => name.clone(),
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let enums_detail = file
+        .enums
+        .keys()
+        .map(|name| match file.enum_docs.get(name) {
+            Some(doc) => format!("{} — {}", name, doc),
+            None => name.clone(),
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let module_doc = file
+        .module_doc
+        .as_ref()
+        .map_or(String::new(), |d| format!(" | Module doc: {}", d));
+
+    format!(
+        "File: {} | Functions: [{}] | Structs: [{}] | Variables: [{}] | Enums: [{}]{}",
+        file.file_name,
+        functions_detail,
+        structs_detail,
+        file.variables.join(", "),
+        enums_detail,
+        module_doc
+    )
+}
+
+/// Render a single `Cargo.toml`'s package/dependency info as prompt lines,
+/// resolving each dependency against the locked versions when available.
+fn describe_dependencies(cargo: &CargoToml, locked: Option<&ResolvedDependencies>) -> Vec<String> {
+    let mut deps = Vec::new();
+
+    if let Some(pkg) = &cargo.package {
+        let pkg_desc = pkg
+            .description
+            .as_ref()
+            .map_or(String::new(), |desc| format!(" - {}", desc));
+        deps.push(format!(
+            "Package: {} v{}{}",
+            pkg.name, pkg.version, pkg_desc
+        ));
+    }
+
+    if let Some(dep_map) = &cargo.dependencies {
+        deps.extend(describe_dep_map("Dependency", dep_map, locked));
+    }
+
+    if let Some(dev_dep_map) = &cargo.dev_dependencies {
+        deps.extend(describe_dep_map("Dev Dependency", dev_dep_map, locked));
+    }
+
+    if let Some(build_dep_map) = &cargo.build_dependencies {
+        deps.extend(describe_dep_map("Build Dependency", build_dep_map, locked));
+    }
+
+    if let Some(targets) = &cargo.target {
+        for (cfg, target_deps) in targets {
+            if let Some(dep_map) = &target_deps.dependencies {
+                let label = format!("Target[{}] Dependency", cfg);
+                deps.extend(describe_dep_map(&label, dep_map, locked));
+            }
+            if let Some(dep_map) = &target_deps.dev_dependencies {
+                let label = format!("Target[{}] Dev Dependency", cfg);
+                deps.extend(describe_dep_map(&label, dep_map, locked));
+            }
+            if let Some(dep_map) = &target_deps.build_dependencies {
+                let label = format!("Target[{}] Build Dependency", cfg);
+                deps.extend(describe_dep_map(&label, dep_map, locked));
+            }
+        }
+    }
+
+    deps
+}
+
+/// Render one dependency table (`[dependencies]`, `[dev-dependencies]`,
+/// `[build-dependencies]`, or a `[target.'cfg(...)']` table) under a given
+/// label, resolving each entry against the locked versions when available.
+fn describe_dep_map(
+    label: &str,
+    dep_map: &HashMap<String, toml::Value>,
+    locked: Option<&ResolvedDependencies>,
+) -> Vec<String> {
+    dep_map
+        .iter()
+        .map(|(dep_name, dep_value)| {
+            let optional = if is_optional_dependency(dep_value) {
+                " (optional)"
+            } else {
+                ""
+            };
+            match locked.and_then(|l| l.versions.get(dep_name)) {
+                Some(locked_version) => format!(
+                    "{}: {} (requires {:?}, locked to {}){}",
+                    label, dep_name, dep_value, locked_version, optional
+                ),
+                None => format!("{}: {}: {:?}{}", label, dep_name, dep_value, optional),
+            }
+        })
+        .collect()
+}
+
+/// A dependency is optional if its inline table sets `optional = true`
+/// (the form that lets a `[features]` entry gate it via `dep:name`).
+fn is_optional_dependency(dep_value: &toml::Value) -> bool {
+    dep_value
+        .as_table()
+        .and_then(|t| t.get("optional"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Render a `[features]` table as prompt lines: the default set, then each
+/// feature and what it enables, distinguishing `dep:name` references to
+/// optional dependencies from plain feature-to-feature implications.
+fn describe_features(cargo: &CargoToml) -> Vec<String> {
+    let mut lines = Vec::new();
+    let Some(features) = &cargo.features else {
+        return lines;
+    };
+
+    if let Some(default) = features.get("default") {
+        lines.push(format!("Default features: [{}]", default.join(", ")));
+    }
+
+    for (name, implies) in features {
+        if name == "default" {
+            continue;
+        }
+        let rendered = implies
+            .iter()
+            .map(|item| match item.strip_prefix("dep:") {
+                Some(dep_name) => format!("optional dependency `{}`", dep_name),
+                None => item.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("Feature: {} -> enables [{}]", name, rendered));
+    }
+
+    lines
+}
+
+pub(crate) fn build_input(path: PathBuf) -> Result<String, Box<dyn std::error::Error>> {
     let mut llm_input = LLMInput::default();
     let repo = explorer::walk_repo(path)?;
     llm_input.project_name = repo.repo_name;
@@ -33,75 +215,82 @@ fn build_input(path: PathBuf) -> Result<String, Box<dyn std::error::Error>> {
         .project_structure
         .push(format!("Folders: {}", repo.folders.join(", ")));
 
-    for file in repo.files {
-        let functions_detail = file
-            .functions
-            .iter()
-            .map(|(name, meta)| {
-                format!(
-                    "{} (params: [{}], returns: {}, visibility: {})",
-                    name,
-                    meta.params.join(", "),
-                    meta.returns,
-                    meta.visibility
-                )
-            })
-            .collect::<Vec<_>>()
-            .join(" ; ");
-
-        let file_details = format!(
-            "File: {} | Functions: [{}] | Structs: [{}] | Variables: [{}] | Enums: [{}]",
-            file.file_name,
-            functions_detail,
-            file.structs
-                .keys()
-                .cloned()
-                .collect::<Vec<String>>()
-                .join(", "),
-            file.variables.join(", "),
-            file.enums
-                .keys()
-                .cloned()
-                .collect::<Vec<String>>()
-                .join(", ")
-        );
-        llm_input.project_structure.push(file_details);
-    }
-
-    // Build dependency information.
+    let locked = repo.locked_dependencies;
     let mut deps = Vec::new();
-    for cargo in repo.dependencies {
-        if let Some(pkg) = cargo.package {
-            let pkg_desc = pkg
-                .description
-                .map_or(String::new(), |desc| format!(" - {}", desc));
-            deps.push(format!(
-                "Package: {} v{}{}",
-                pkg.name, pkg.version, pkg_desc
-            ));
+    let mut features = Vec::new();
+    let mut local_crate_names: HashSet<String> = HashSet::new();
+
+    if repo.workspace_members.is_empty() {
+        for file in &repo.files {
+            llm_input.project_structure.push(describe_file(file));
+        }
+
+        for cargo in &repo.dependencies {
+            if let Some(pkg) = &cargo.package {
+                local_crate_names.insert(pkg.name.clone());
+            }
+            deps.extend(describe_dependencies(cargo, locked.as_ref()));
+            features.extend(describe_features(cargo));
         }
+    } else {
+        for member in &repo.workspace_members {
+            local_crate_names.insert(member.name.clone());
+
+            llm_input
+                .project_structure
+                .push(format!("Crate: {}", member.name));
 
-        if let Some(dep_map) = cargo.dependencies {
-            for (dep_name, dep_value) in dep_map {
-                deps.push(format!("Dependency: {}: {:?}", dep_name, dep_value));
+            for file in &member.files {
+                llm_input.project_structure.push(describe_file(file));
+            }
+
+            for cargo in &member.dependencies {
+                deps.extend(
+                    describe_dependencies(cargo, locked.as_ref())
+                        .into_iter()
+                        .map(|line| format!("[{}] {}", member.name, line)),
+                );
+                features.extend(
+                    describe_features(cargo)
+                        .into_iter()
+                        .map(|line| format!("[{}] {}", member.name, line)),
+                );
             }
         }
+    }
 
-        if let Some(dev_dep_map) = cargo.dev_dependencies {
-            for (dep_name, dep_value) in dev_dep_map {
-                deps.push(format!("Dev Dependency: {}: {:?}", dep_name, dep_value));
+    if let Some(locked) = &locked {
+        for (name, version) in &locked.versions {
+            // A workspace member (or the sole crate) is reported via its own
+            // "Crate"/dependency entries above; listing it again here as a
+            // "Locked: ..." line would wrongly imply it depends on itself.
+            if local_crate_names.contains(name) {
+                continue;
             }
+
+            let transitive_count = locked.reverse_edges.get(name).map_or(0, |v| v.len());
+            let source = locked
+                .sources
+                .get(name)
+                .map_or(String::new(), |src| format!(", source: {}", src));
+            deps.push(format!(
+                "Locked: {} v{} (depended on by {} other locked package(s){})",
+                name, version, transitive_count, source
+            ));
         }
     }
-    llm_input.project_dependencies = deps.join(", ");
+
+    llm_input.project_dependencies = deps;
+    llm_input.project_features = features;
 
     llm_input.prompt_directives = r#"You are a README generator.
-You will be provided with a project name, its language, its dependencies, and its structure.
+You will be provided with a project name, its language, its dependencies, its feature flags, and its structure.
 You will generate a README file for the project.
 The README should include:
 - Project name
 - Project language
 - Project dependencies
+- Feature flags and how to enable them via `cargo --features`
 - Project structure
 - A brief description of each file and its contents
 - How to run the project
@@ -115,3 +304,71 @@ The README should be easy to read and understand."#
     // Serialize to JSON and return.
     llm_input.to_json().map_err(|e| e.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_features_reports_default_and_dep_references() {
+        let cargo: CargoToml = toml::from_str(
+            r#"
+            [package]
+            name = "demo"
+            version = "0.1.0"
+
+            [dependencies]
+            tracing = { version = "0.1", optional = true }
+
+            [features]
+            default = ["tracing-support"]
+            tracing-support = ["dep:tracing"]
+            "#,
+        )
+        .unwrap();
+
+        let lines = describe_features(&cargo);
+        assert!(lines.contains(&"Default features: [tracing-support]".to_string()));
+        assert!(lines.contains(
+            &"Feature: tracing-support -> enables [optional dependency `tracing`]".to_string()
+        ));
+    }
+
+    #[test]
+    fn describe_dependencies_labels_build_and_target_specific_deps() {
+        let cargo: CargoToml = toml::from_str(
+            r#"
+            [build-dependencies]
+            cc = "1.0"
+
+            [target.'cfg(windows)'.dependencies]
+            winapi = "0.3"
+            "#,
+        )
+        .unwrap();
+
+        let lines = describe_dependencies(&cargo, None);
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("Build Dependency: cc")));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("Target[cfg(windows)] Dependency: winapi")));
+    }
+
+    #[test]
+    fn is_optional_dependency_reads_inline_table_flag() {
+        let cargo: CargoToml = toml::from_str(
+            r#"
+            [dependencies]
+            tracing = { version = "0.1", optional = true }
+            serde = "1.0"
+            "#,
+        )
+        .unwrap();
+
+        let deps = cargo.dependencies.as_ref().unwrap();
+        assert!(is_optional_dependency(&deps["tracing"]));
+        assert!(!is_optional_dependency(&deps["serde"]));
+    }
+}